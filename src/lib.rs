@@ -97,18 +97,123 @@
 //! }
 //! ```
 
+use std::collections::HashSet;
+use std::error::Error;
+
 use chrono::{DateTime, Duration, FixedOffset, Utc};
 pub use cron::Schedule;
 pub use uuid::Uuid;
 
+/// A source of the current time, abstracted so schedulers can be driven by
+/// something other than the real wall clock.
+///
+/// The default [`RealTime`] provider simply wraps [`Utc::now`]. Tests that
+/// need deterministic, fast-forwardable behavior should use [`MockTime`]
+/// instead.
+pub trait TimeProvider {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The default [`TimeProvider`], backed by the real system clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RealTime;
+
+impl TimeProvider for RealTime {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A [`TimeProvider`] whose clock is set and advanced manually, for
+/// deterministic tests.
+///
+/// ```rust,ignore
+/// let mut sched = JobScheduler::with_time_provider(MockTime::new(Utc::now()));
+/// sched.add(Job::new("0/10 * * * * *".parse().unwrap(), || {
+///     println!("ticked");
+/// }));
+/// sched.tick();
+/// sched.advance_time(chrono::Duration::seconds(10));
+/// sched.tick();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MockTime {
+    now: DateTime<Utc>,
+}
+
+impl MockTime {
+    /// Create a `MockTime` starting at `now`.
+    #[inline]
+    #[must_use]
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { now }
+    }
+
+    /// Move the mock clock forward by `duration`.
+    #[inline]
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+    }
+
+    /// Set the mock clock to an arbitrary point in time.
+    #[inline]
+    pub fn set(&mut self, now: DateTime<Utc>) {
+        self.now = now;
+    }
+}
+
+impl TimeProvider for MockTime {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+/// A boxed error-handling callback, invoked with the id of the job whose
+/// fallible closure returned `Err`.
+type ErrorHandler<'a> = Box<dyn FnMut(Uuid, Box<dyn Error + Send>) + Send + 'a>;
+
+/// The closure a [`Job`] runs when it fires.
+///
+/// A job is either infallible, or fallible and reports its errors to the
+/// owning [`JobScheduler`]'s error handler.
+enum JobAction<'a> {
+    Infallible(Box<dyn FnMut() + Send + 'a>),
+    Fallible(Box<dyn FnMut() -> Result<(), Box<dyn Error + Send>> + Send + 'a>),
+}
+
+/// What causes a [`Job`] to fire.
+pub enum Trigger {
+    /// Fire according to a cron [`Schedule`].
+    Cron(Box<Schedule>),
+    /// Fire exactly once, as soon as `now` reaches the given instant.
+    Once(DateTime<FixedOffset>),
+    /// Fire every `period`, starting one period after the first tick;
+    /// `next` is `None` until then. Querying the next fire time before the
+    /// first tick reports one period from the time of the query, without
+    /// storing it.
+    Interval {
+        period: Duration,
+        next: Option<DateTime<FixedOffset>>,
+    },
+}
+
 /// A schedulable `Job`.
 pub struct Job<'a> {
-    schedule: Schedule,
-    run: Box<dyn (FnMut()) + Send + 'a>,
+    trigger: Trigger,
+    run: JobAction<'a>,
+    on_start: Option<Box<dyn FnMut(Uuid) + Send + 'a>>,
+    on_done: Option<Box<dyn FnMut(Uuid) + Send + 'a>>,
+    on_removed: Option<Box<dyn FnMut(Uuid) + Send + 'a>>,
     last_tick: Option<DateTime<FixedOffset>>,
+    completed: bool,
+    runs_remaining: Option<usize>,
     limit_missed_runs: usize,
     job_id: Uuid,
     timezone: FixedOffset,
+    tags: HashSet<String>,
 }
 
 impl<'a> Job<'a> {
@@ -126,37 +231,255 @@ impl<'a> Job<'a> {
         T: FnMut() + Send + 'a,
     {
         Self {
-            schedule,
-            run: Box::new(run),
+            trigger: Trigger::Cron(Box::new(schedule)),
+            run: JobAction::Infallible(Box::new(run)),
+            on_start: None,
+            on_done: None,
+            on_removed: None,
             last_tick: None,
+            completed: false,
+            runs_remaining: None,
             limit_missed_runs: 1,
             job_id: Uuid::new_v4(),
             timezone: FixedOffset::east_opt(0).unwrap(),
+            tags: HashSet::new(),
         }
     }
 
-    fn tick(&mut self) {
-        let now = Utc::now().with_timezone(&self.timezone);
+    /// Create a job that fires exactly once, as soon as `now` reaches `instant`.
+    ///
+    /// ```rust,ignore
+    /// Job::once(Utc::now().into(), || println!("fired once") );
+    /// ```
+    #[inline]
+    pub fn once<T>(instant: DateTime<FixedOffset>, run: T) -> Self
+    where
+        T: FnMut() + Send + 'a,
+    {
+        Self {
+            trigger: Trigger::Once(instant),
+            run: JobAction::Infallible(Box::new(run)),
+            on_start: None,
+            on_done: None,
+            on_removed: None,
+            last_tick: None,
+            completed: false,
+            runs_remaining: None,
+            limit_missed_runs: 1,
+            job_id: Uuid::new_v4(),
+            timezone: FixedOffset::east_opt(0).unwrap(),
+            tags: HashSet::new(),
+        }
+    }
 
-        let last_tick = match self.last_tick.replace(now) {
-            Some(last_tick) => last_tick,
-            None => return,
-        };
+    /// Create a job that fires every `period`, starting one period after it
+    /// is first ticked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive, since a zero or negative period
+    /// would never advance the catch-up loop in [`Job::tick`] past `now`.
+    ///
+    /// ```rust,ignore
+    /// Job::interval(chrono::Duration::seconds(30), || println!("tick") );
+    /// ```
+    #[inline]
+    pub fn interval<T>(period: Duration, run: T) -> Self
+    where
+        T: FnMut() + Send + 'a,
+    {
+        assert!(period > Duration::zero(), "Job::interval period must be positive");
+
+        Self {
+            trigger: Trigger::Interval { period, next: None },
+            run: JobAction::Infallible(Box::new(run)),
+            on_start: None,
+            on_done: None,
+            on_removed: None,
+            last_tick: None,
+            completed: false,
+            runs_remaining: None,
+            limit_missed_runs: 1,
+            job_id: Uuid::new_v4(),
+            timezone: FixedOffset::east_opt(0).unwrap(),
+            tags: HashSet::new(),
+        }
+    }
+
+    /// Create a new job whose closure can fail.
+    ///
+    /// Errors returned by `run` are routed to the owning
+    /// [`JobScheduler`]'s error handler, set via
+    /// [`JobScheduler::set_error_handler`], instead of being swallowed or
+    /// panicking.
+    ///
+    /// ```rust,ignore
+    /// Job::new_fallible(s, || {
+    ///     do_something()?;
+    ///     Ok(())
+    /// });
+    /// ```
+    #[inline]
+    pub fn new_fallible<T>(schedule: Schedule, run: T) -> Self
+    where
+        T: FnMut() -> Result<(), Box<dyn Error + Send>> + Send + 'a,
+    {
+        Self {
+            trigger: Trigger::Cron(Box::new(schedule)),
+            run: JobAction::Fallible(Box::new(run)),
+            on_start: None,
+            on_done: None,
+            on_removed: None,
+            last_tick: None,
+            completed: false,
+            runs_remaining: None,
+            limit_missed_runs: 1,
+            job_id: Uuid::new_v4(),
+            timezone: FixedOffset::east_opt(0).unwrap(),
+            tags: HashSet::new(),
+        }
+    }
 
-        if self.limit_missed_runs > 0 {
-            for event in self.schedule.after(&last_tick).take(self.limit_missed_runs) {
-                if event > now {
-                    break;
+    /// Register a callback invoked right before each run of this job.
+    #[inline]
+    pub fn on_start<F>(&mut self, f: F)
+    where
+        F: FnMut(Uuid) + Send + 'a,
+    {
+        self.on_start = Some(Box::new(f));
+    }
+
+    /// Register a callback invoked right after each run of this job.
+    #[inline]
+    pub fn on_done<F>(&mut self, f: F)
+    where
+        F: FnMut(Uuid) + Send + 'a,
+    {
+        self.on_done = Some(Box::new(f));
+    }
+
+    /// Register a callback invoked when this job is removed from its
+    /// `JobScheduler`: via [`JobScheduler::remove`],
+    /// [`JobScheduler::remove_by_tag`] or [`JobScheduler::clear`], or when
+    /// [`JobScheduler::tick`] drops it after it has run out of fires.
+    #[inline]
+    pub fn on_removed<F>(&mut self, f: F)
+    where
+        F: FnMut(Uuid) + Send + 'a,
+    {
+        self.on_removed = Some(Box::new(f));
+    }
+
+    pub(crate) fn tick<Tp: TimeProvider>(
+        &mut self,
+        time_provider: &Tp,
+        on_error: &mut dyn FnMut(Uuid, Box<dyn Error + Send>),
+    ) {
+        let now = time_provider.now().with_timezone(&self.timezone);
+
+        let fire_count = match &mut self.trigger {
+            Trigger::Cron(schedule) => {
+                let last_tick = match self.last_tick.replace(now) {
+                    Some(last_tick) => last_tick,
+                    None => return,
+                };
+
+                if self.limit_missed_runs > 0 {
+                    schedule
+                        .after(&last_tick)
+                        .take(self.limit_missed_runs)
+                        .take_while(|event| *event <= now)
+                        .count()
+                } else {
+                    schedule.after(&last_tick).take_while(|event| *event <= now).count()
+                }
+            }
+            Trigger::Once(instant) => {
+                if !self.completed && now >= *instant {
+                    self.completed = true;
+                    1
+                } else {
+                    0
                 }
-                (self.run)();
             }
-        } else {
-            for event in self.schedule.after(&last_tick) {
-                if event > now {
-                    break;
+            Trigger::Interval { period, next } => {
+                let next = next.get_or_insert(now + *period);
+                let mut count = 0;
+                while *next <= now && (self.limit_missed_runs == 0 || count < self.limit_missed_runs) {
+                    *next += *period;
+                    count += 1;
                 }
-                (self.run)();
+                count
             }
+        };
+
+        let fire_count = self.runs_remaining.as_mut().map_or(fire_count, |remaining| {
+            let fire_count = fire_count.min(*remaining);
+            *remaining -= fire_count;
+            fire_count
+        });
+
+        for _ in 0..fire_count {
+            Self::fire(
+                &mut self.run,
+                &mut self.on_start,
+                &mut self.on_done,
+                self.job_id,
+                on_error,
+            );
+        }
+    }
+
+    /// Returns `true` once this job has no more runs left, either because a
+    /// one-shot [`Trigger::Once`] already fired or because a run limit set
+    /// via [`Job::count`] has been reached.
+    pub(crate) fn is_finished(&self) -> bool {
+        matches!(self.runs_remaining, Some(0)) || (matches!(self.trigger, Trigger::Once(_)) && self.completed)
+    }
+
+    /// This job's `Uuid`.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.job_id
+    }
+
+    /// The next instant at which this job is due to fire, if any, as of `now`.
+    ///
+    /// Takes `now` explicitly (rather than reading the real clock, as
+    /// [`cron::Schedule::upcoming`] does) so the result stays correct for
+    /// schedulers driven by a [`MockTime`] provider.
+    pub(crate) fn next_event(&self, now: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+        match &self.trigger {
+            Trigger::Cron(schedule) => schedule.after(&now).take(1).next(),
+            Trigger::Once(instant) => (!self.completed).then_some(*instant),
+            Trigger::Interval { period, next } => Some(next.unwrap_or(now + *period)),
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn fire(
+        run: &mut JobAction<'a>,
+        on_start: &mut Option<Box<dyn FnMut(Uuid) + Send + 'a>>,
+        on_done: &mut Option<Box<dyn FnMut(Uuid) + Send + 'a>>,
+        job_id: Uuid,
+        on_error: &mut dyn FnMut(Uuid, Box<dyn Error + Send>),
+    ) {
+        if let Some(on_start) = on_start {
+            on_start(job_id);
+        }
+
+        match run {
+            JobAction::Infallible(run) => run(),
+            JobAction::Fallible(run) => {
+                if let Err(err) = run() {
+                    on_error(job_id, err);
+                }
+            }
+        }
+
+        if let Some(on_done) = on_done {
+            on_done(job_id);
         }
     }
 
@@ -185,25 +508,120 @@ impl<'a> Job<'a> {
     pub fn last_tick(&mut self, last_tick: Option<DateTime<FixedOffset>>) {
         self.last_tick = last_tick;
     }
+
+    /// Retire this job after it has fired `n` more times in total.
+    ///
+    /// Once exhausted, the job is dropped from its `JobScheduler` at the end
+    /// of the [`JobScheduler::tick`] in which it fires for the last time,
+    /// instead of running forever.
+    ///
+    /// ```rust,ignore
+    /// let mut job = Job::new("0/1 * * * * *".parse().unwrap(), || {
+    ///     println!("I run at most 5 times!");
+    /// });
+    /// job.count(5);
+    /// ```
+    #[inline]
+    pub fn count(&mut self, n: usize) {
+        self.runs_remaining = Some(n);
+    }
+
+    /// Shorthand for `job.count(1)`: retire this job after it fires one
+    /// more time. Named `run_once` rather than `once`, since [`Job::once`]
+    /// is already the constructor for a [`Trigger::Once`] job.
+    ///
+    /// ```rust,ignore
+    /// let mut job = Job::new("0/1 * * * * *".parse().unwrap(), || {
+    ///     println!("I run exactly once!");
+    /// });
+    /// job.run_once();
+    /// ```
+    #[inline]
+    pub fn run_once(&mut self) {
+        self.count(1);
+    }
+
+    /// Tag this job so it can be bulk-queried or removed later via
+    /// [`JobScheduler::jobs_by_tag`] or [`JobScheduler::remove_by_tag`].
+    /// Chainable, so a job can carry more than one tag.
+    ///
+    /// ```rust,ignore
+    /// let mut job = Job::new("0/1 * * * * *".parse().unwrap(), || {
+    ///     println!("I run maintenance!");
+    /// });
+    /// job.tag("maintenance").tag("user-123");
+    /// ```
+    #[inline]
+    pub fn tag(&mut self, tag: &str) -> &mut Self {
+        self.tags.insert(tag.to_owned());
+        self
+    }
 }
 
 /// The JobScheduler contains and executes the scheduled jobs.
-pub struct JobScheduler<'a> {
+///
+/// It is generic over a [`TimeProvider`] so that, in tests, the clock can be
+/// advanced manually via [`JobScheduler::with_time_provider`] and
+/// [`JobScheduler::advance_time`] instead of sleeping in real time.
+pub struct JobScheduler<'a, Tp: TimeProvider = RealTime> {
     jobs: Vec<Job<'a>>,
     timezone: FixedOffset,
+    time_provider: Tp,
+    error_handler: Option<ErrorHandler<'a>>,
 }
 
-impl<'a> JobScheduler<'a> {
-    /// Create a new `JobScheduler`.
+impl<'a> JobScheduler<'a, RealTime> {
+    /// Create a new `JobScheduler` driven by the real system clock.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
+        Self::with_time_provider(RealTime)
+    }
+}
+
+impl<'a> JobScheduler<'a, MockTime> {
+    /// Advance the scheduler's mock clock by `duration`.
+    ///
+    /// Only available on schedulers created with a [`MockTime`] provider.
+    #[inline]
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.time_provider.advance(duration);
+    }
+}
+
+impl<'a, Tp: TimeProvider> JobScheduler<'a, Tp> {
+    /// Create a new `JobScheduler` driven by the given [`TimeProvider`].
+    ///
+    /// ```rust,ignore
+    /// let mut sched = JobScheduler::with_time_provider(MockTime::new(Utc::now()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_time_provider(time_provider: Tp) -> Self {
         JobScheduler {
             jobs: Vec::new(),
             timezone: FixedOffset::east_opt(0).unwrap(),
+            time_provider,
+            error_handler: None,
         }
     }
 
+    /// Register a callback invoked whenever a fallible job (see
+    /// [`Job::new_fallible`]) returns an `Err` during a tick.
+    ///
+    /// ```rust,ignore
+    /// sched.set_error_handler(|job_id, err| {
+    ///     eprintln!("job {job_id} failed: {err}");
+    /// });
+    /// ```
+    #[inline]
+    pub fn set_error_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(Uuid, Box<dyn Error + Send>) + Send + 'a,
+    {
+        self.error_handler = Some(Box::new(handler));
+    }
+
     /// Add a job to the `JobScheduler`
     ///
     /// ```rust,ignore
@@ -242,16 +660,73 @@ impl<'a> JobScheduler<'a> {
         }
 
         if let Some(index) = found_index {
-            self.jobs.remove(index);
+            let mut job = self.jobs.remove(index);
+            if let Some(on_removed) = &mut job.on_removed {
+                on_removed(job.job_id);
+            }
         }
 
         found_index.is_some()
     }
 
+    /// Remove every job tagged with `tag`, firing each one's `on_removed`
+    /// hook. Returns how many jobs were removed.
+    ///
+    /// ```rust,ignore
+    /// sched.remove_by_tag("maintenance");
+    /// ```
+    pub fn remove_by_tag(&mut self, tag: &str) -> usize {
+        let mut removed = 0;
+        self.jobs.retain_mut(|job| {
+            if job.tags.contains(tag) {
+                if let Some(on_removed) = &mut job.on_removed {
+                    on_removed(job.job_id);
+                }
+                removed += 1;
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+
+    /// Return the `Uuid`s of every job tagged with `tag`.
+    ///
+    /// ```rust,ignore
+    /// let maintenance_jobs = sched.jobs_by_tag("maintenance");
+    /// ```
+    #[must_use]
+    pub fn jobs_by_tag(&self, tag: &str) -> Vec<Uuid> {
+        self.jobs
+            .iter()
+            .filter(|job| job.tags.contains(tag))
+            .map(|job| job.job_id)
+            .collect()
+    }
+
+    /// Remove every job from the scheduler, firing each one's `on_removed`
+    /// hook.
+    ///
+    /// ```rust,ignore
+    /// sched.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        for mut job in self.jobs.drain(..) {
+            if let Some(on_removed) = &mut job.on_removed {
+                on_removed(job.job_id);
+            }
+        }
+    }
+
     /// The `tick` method increments time for the JobScheduler and executes
     /// any pending jobs. It is recommended to sleep for at least 500
     /// milliseconds between invocations of this method.
     ///
+    /// Jobs that have run out of fires, either a one-shot [`Trigger::Once`]
+    /// or one bounded via [`Job::count`], are dropped from the scheduler and
+    /// their `Uuid`s are returned.
+    ///
     /// ```rust,ignore
     /// loop {
     ///     sched.tick();
@@ -259,10 +734,34 @@ impl<'a> JobScheduler<'a> {
     /// }
     /// ```
     #[inline]
-    pub fn tick(&mut self) {
-        for job in &mut self.jobs {
-            job.tick();
+    pub fn tick(&mut self) -> Vec<Uuid> {
+        let Self {
+            jobs,
+            time_provider,
+            error_handler,
+            ..
+        } = self;
+
+        for job in jobs.iter_mut() {
+            match error_handler {
+                Some(handler) => job.tick(time_provider, &mut |job_id, err| handler(job_id, err)),
+                None => job.tick(time_provider, &mut |_, _| {}),
+            }
         }
+
+        let mut finished = Vec::new();
+        jobs.retain_mut(|job| {
+            if job.is_finished() {
+                if let Some(on_removed) = &mut job.on_removed {
+                    on_removed(job.job_id);
+                }
+                finished.push(job.job_id);
+                false
+            } else {
+                true
+            }
+        });
+        finished
     }
 
     /// The `time_till_next_job` method returns the duration till the next job
@@ -282,18 +781,17 @@ impl<'a> JobScheduler<'a> {
             return core::time::Duration::from_millis(500);
         }
 
-        let timezone = self.timezone;
         let mut duration = Duration::zero();
-        let now = Utc::now().with_timezone(&timezone);
+        let now = self.time_provider.now().with_timezone(&self.timezone);
         for job in &self.jobs {
-            for event in job.schedule.upcoming(timezone).take(1) {
+            if let Some(event) = job.next_event(now) {
                 let d = event - now;
                 if duration.is_zero() || d < duration {
                     duration = d;
                 }
             }
         }
-        duration.to_std().unwrap()
+        duration.to_std().unwrap_or(core::time::Duration::ZERO)
     }
 
     /// `JobScheduler` has UTC timezone by default
@@ -311,8 +809,264 @@ impl<'a> JobScheduler<'a> {
     }
 }
 
-impl<'a> Default for JobScheduler<'a> {
+impl<'a> Default for JobScheduler<'a, RealTime> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// An async driver for [`JobScheduler`] that owns its own tick loop, enabled
+/// via the `tokio` feature.
+#[cfg(feature = "tokio")]
+mod tokio_driver;
+
+#[cfg(feature = "tokio")]
+pub use tokio_driver::JobSchedulerHandle;
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use super::{DateTime, Duration, Job, JobScheduler, MockTime, Utc};
+
+    #[derive(Debug)]
+    struct BoomError;
+
+    impl fmt::Display for BoomError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "boom")
+        }
+    }
+
+    impl std::error::Error for BoomError {}
+
+    fn mock_sched(now: DateTime<Utc>) -> JobScheduler<'static, MockTime> {
+        JobScheduler::with_time_provider(MockTime::new(now))
+    }
+
+    #[test]
+    fn cron_job_does_not_fire_before_its_schedule() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+        let runs = Arc::new(AtomicUsize::new(0));
+        let job_runs = Arc::clone(&runs);
+        sched.add(Job::new("0/10 * * * * *".parse().unwrap(), move || {
+            job_runs.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        // The first tick only establishes `last_tick`; nothing has fired yet.
+        sched.tick();
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+
+        sched.advance_time(Duration::seconds(10));
+        sched.tick();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn once_job_fires_and_is_removed_on_the_same_tick() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+        let runs = Arc::new(AtomicUsize::new(0));
+        let job_runs = Arc::clone(&runs);
+        let job_id = sched.add(Job::once(now.into(), move || {
+            job_runs.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let removed = sched.tick();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(removed, vec![job_id]);
+    }
+
+    #[test]
+    fn time_till_next_job_does_not_panic_for_an_already_past_instant() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+        sched.add(Job::once((now - Duration::seconds(5)).into(), || {}));
+
+        assert_eq!(sched.time_till_next_job(), core::time::Duration::ZERO);
+    }
+
+    #[test]
+    fn time_till_next_job_tracks_the_mock_clock_for_cron_jobs() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now + Duration::days(365));
+        sched.add(Job::new("0/10 * * * * *".parse().unwrap(), || {}));
+
+        let till = sched.time_till_next_job();
+        assert!(till > core::time::Duration::ZERO && till <= core::time::Duration::from_secs(10));
+    }
+
+    #[test]
+    fn interval_job_starts_counting_from_first_tick_not_construction() {
+        let construction_time = Utc::now();
+        let scheduler_time = construction_time + Duration::days(1);
+        let mut sched = mock_sched(scheduler_time);
+        sched.add(Job::interval(Duration::seconds(30), || {}));
+
+        let till = sched.time_till_next_job();
+        assert_eq!(till, core::time::Duration::from_secs(30));
+    }
+
+    #[test]
+    #[should_panic(expected = "period must be positive")]
+    fn interval_rejects_a_non_positive_period() {
+        Job::interval(Duration::zero(), || {});
+    }
+
+    #[test]
+    fn run_once_retires_a_cron_job_after_a_single_fire() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+        let runs = Arc::new(AtomicUsize::new(0));
+        let job_runs = Arc::clone(&runs);
+        let mut job = Job::new("0/10 * * * * *".parse().unwrap(), move || {
+            job_runs.fetch_add(1, Ordering::SeqCst);
+        });
+        job.run_once();
+        let job_id = sched.add(job);
+
+        sched.tick();
+        sched.advance_time(Duration::seconds(10));
+        let removed = sched.tick();
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(removed, vec![job_id]);
+    }
+
+    #[test]
+    fn fallible_job_errors_are_routed_to_the_error_handler() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let handler_errors = Arc::clone(&errors);
+        sched.set_error_handler(move |job_id, err| {
+            handler_errors.lock().unwrap().push((job_id, err.to_string()));
+        });
+
+        let job_id = sched.add(Job::new_fallible("0/10 * * * * *".parse().unwrap(), || Err(Box::new(BoomError))));
+
+        // The first tick only establishes `last_tick`; nothing has fired yet.
+        sched.tick();
+        sched.advance_time(Duration::seconds(10));
+        sched.tick();
+
+        assert_eq!(*errors.lock().unwrap(), vec![(job_id, "boom".to_owned())]);
+    }
+
+    #[test]
+    fn fallible_job_with_no_error_handler_does_not_panic() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+        sched.add(Job::new_fallible("0/10 * * * * *".parse().unwrap(), || Err(Box::new(BoomError))));
+
+        sched.tick();
+        sched.advance_time(Duration::seconds(10));
+        sched.tick();
+    }
+
+    #[test]
+    fn lifecycle_hooks_fire_in_order_around_each_run() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut job = Job::new("0/10 * * * * *".parse().unwrap(), {
+            let events = Arc::clone(&events);
+            move || events.lock().unwrap().push("run")
+        });
+        job.on_start({
+            let events = Arc::clone(&events);
+            move |_| events.lock().unwrap().push("start")
+        });
+        job.on_done({
+            let events = Arc::clone(&events);
+            move |_| events.lock().unwrap().push("done")
+        });
+        sched.add(job);
+
+        // The first tick only establishes `last_tick`; nothing has fired yet.
+        sched.tick();
+        assert!(events.lock().unwrap().is_empty());
+
+        sched.advance_time(Duration::seconds(10));
+        sched.tick();
+
+        assert_eq!(*events.lock().unwrap(), vec!["start", "run", "done"]);
+    }
+
+    #[test]
+    fn on_removed_fires_for_explicit_remove_run_out_and_remove_by_tag_and_clear() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+
+        let removed = Arc::new(Mutex::new(Vec::new()));
+
+        let mut explicit = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        let explicit_removed = Arc::clone(&removed);
+        explicit.on_removed(move |id| explicit_removed.lock().unwrap().push(id));
+        let explicit_id = sched.add(explicit);
+
+        let mut run_once_job = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        run_once_job.run_once();
+        let run_once_removed = Arc::clone(&removed);
+        run_once_job.on_removed(move |id| run_once_removed.lock().unwrap().push(id));
+        let run_once_id = sched.add(run_once_job);
+
+        let mut tagged = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        tagged.tag("maintenance");
+        let tagged_removed = Arc::clone(&removed);
+        tagged.on_removed(move |id| tagged_removed.lock().unwrap().push(id));
+        let tagged_id = sched.add(tagged);
+
+        let mut cleared = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        let cleared_removed = Arc::clone(&removed);
+        cleared.on_removed(move |id| cleared_removed.lock().unwrap().push(id));
+        sched.add(cleared);
+
+        assert!(sched.remove(explicit_id));
+        assert_eq!(*removed.lock().unwrap(), vec![explicit_id]);
+
+        sched.tick();
+        sched.advance_time(Duration::seconds(10));
+        let ticked_away = sched.tick();
+        assert_eq!(ticked_away, vec![run_once_id]);
+        assert_eq!(*removed.lock().unwrap(), vec![explicit_id, run_once_id]);
+
+        assert_eq!(sched.remove_by_tag("maintenance"), 1);
+        assert_eq!(*removed.lock().unwrap(), vec![explicit_id, run_once_id, tagged_id]);
+
+        sched.clear();
+        assert_eq!(removed.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn jobs_by_tag_finds_jobs_carrying_any_number_of_tags() {
+        let now = Utc::now();
+        let mut sched = mock_sched(now);
+
+        let mut shared_and_own = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        shared_and_own.tag("shared").tag("own");
+        let shared_and_own_id = sched.add(shared_and_own);
+
+        let mut shared_only = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        shared_only.tag("shared");
+        let shared_only_id = sched.add(shared_only);
+
+        let mut untagged = Job::new("0/10 * * * * *".parse().unwrap(), || {});
+        untagged.tag("other");
+        sched.add(untagged);
+
+        assert_eq!(sched.jobs_by_tag("own"), vec![shared_and_own_id]);
+        assert!(sched.jobs_by_tag("does-not-exist").is_empty());
+
+        let mut shared = sched.jobs_by_tag("shared");
+        shared.sort();
+        let mut expected = vec![shared_and_own_id, shared_only_id];
+        expected.sort();
+        assert_eq!(shared, expected);
+    }
+}
@@ -0,0 +1,295 @@
+//! Requires the optional `tokio` feature (a `tokio` dependency with the
+//! `rt`, `sync`, `time`, and `macros` features enabled).
+//!
+//! The synchronous [`JobScheduler::tick`] requires callers to hand-roll
+//! their own `loop { sched.tick(); sleep(..); }` driver, and every job runs
+//! inline on that thread, so a slow job blocks all the others. Handing the
+//! scheduler to [`JobScheduler::start`] instead spawns a background task
+//! that sleeps until the next job is due between ticks (computed as
+//! [`JobScheduler::time_till_next_job`] does, except that jobs still running
+//! are skipped and 500 milliseconds is used when no job is due) and
+//! runs each job on its own [`tokio::task::spawn_blocking`] task, so a job
+//! whose closure blocks (sleeps, blocking I/O, heavy CPU work) never stalls
+//! dispatch of the others or the coordinator's own command processing.
+
+use std::error::Error;
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::{Job, JobScheduler, TimeProvider, Uuid};
+
+enum Command {
+    Add(Box<Job<'static>>),
+    Remove(Uuid),
+    Shutdown,
+}
+
+/// A handle to a [`JobScheduler`] running on a background tokio task,
+/// returned by [`JobScheduler::start`].
+///
+/// Dropping every clone of the handle does not stop the background task;
+/// call [`JobSchedulerHandle::shutdown`] to do that.
+#[derive(Clone)]
+pub struct JobSchedulerHandle {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl JobSchedulerHandle {
+    /// Add a job to the running scheduler.
+    pub fn add(&self, job: Job<'static>) -> Uuid {
+        let job_id = job.id();
+        let _ = self.commands.send(Command::Add(Box::new(job)));
+        job_id
+    }
+
+    /// Remove a job from the running scheduler.
+    pub fn remove(&self, job_id: Uuid) {
+        let _ = self.commands.send(Command::Remove(job_id));
+    }
+
+    /// Stop the scheduler's background task.
+    pub fn shutdown(&self) {
+        let _ = self.commands.send(Command::Shutdown);
+    }
+}
+
+impl<Tp> JobScheduler<'static, Tp>
+where
+    Tp: TimeProvider + Send + Sync + 'static,
+{
+    /// Hand this scheduler to a background tokio task that drives its own
+    /// tick loop and runs each job concurrently.
+    ///
+    /// ```rust,ignore
+    /// let sched = JobScheduler::new();
+    /// let (handle, join_handle) = sched.start();
+    /// handle.add(Job::new("0/10 * * * * *".parse().unwrap(), || {
+    ///     println!("ticked");
+    /// }));
+    /// // ...
+    /// handle.shutdown();
+    /// join_handle.await.unwrap();
+    /// ```
+    #[must_use]
+    pub fn start(self) -> (JobSchedulerHandle, JoinHandle<()>) {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let self_tx = tx.clone();
+
+        let timezone = self.timezone;
+        let time_provider = Arc::new(self.time_provider);
+        let error_handler = Arc::new(Mutex::new(self.error_handler));
+        let mut jobs: Vec<(Uuid, Arc<Mutex<Job<'static>>>)> = self
+            .jobs
+            .into_iter()
+            .map(|job| (job.id(), Arc::new(Mutex::new(job))))
+            .collect();
+
+        let join_handle = tokio::spawn(async move {
+            loop {
+                let sleep_for = time_till_next_job(&jobs, &*time_provider, timezone);
+
+                tokio::select! {
+                    () = tokio::time::sleep(sleep_for) => {
+                        for (job_id, job) in &jobs {
+                            let job_id = *job_id;
+                            let job = Arc::clone(job);
+                            let time_provider = Arc::clone(&time_provider);
+                            let error_handler = Arc::clone(&error_handler);
+                            let self_tx = self_tx.clone();
+
+                            tokio::spawn(async move {
+                                let job_guard = job.lock_owned().await;
+
+                                // Run the job's closure (which may block) on
+                                // the blocking thread pool instead of inline
+                                // on this async task, so it can't stall the
+                                // runtime's worker threads. Errors are
+                                // collected locally rather than routed
+                                // through the shared error handler here, so
+                                // a slow job doesn't also hold up every
+                                // other job's error reporting.
+                                let Ok((is_finished, errors)) = tokio::task::spawn_blocking(move || {
+                                    let mut job_guard = job_guard;
+                                    let mut errors: Vec<(Uuid, Box<dyn Error + Send>)> = Vec::new();
+                                    job_guard.tick(&*time_provider, &mut |job_id, err| errors.push((job_id, err)));
+                                    (job_guard.is_finished(), errors)
+                                })
+                                .await
+                                else {
+                                    return;
+                                };
+
+                                if !errors.is_empty() {
+                                    let mut error_handler = error_handler.lock().await;
+                                    for (job_id, err) in errors {
+                                        if let Some(handler) = error_handler.as_mut() {
+                                            handler(job_id, err);
+                                        }
+                                    }
+                                }
+
+                                if is_finished {
+                                    let _ = self_tx.send(Command::Remove(job_id));
+                                }
+                            });
+                        }
+                    }
+                    command = rx.recv() => {
+                        match command {
+                            Some(Command::Add(job)) => {
+                                let mut job = *job;
+                                job.timezone = timezone;
+                                jobs.push((job.id(), Arc::new(Mutex::new(job))));
+                            }
+                            Some(Command::Remove(job_id)) => {
+                                let mut removed = None;
+                                jobs.retain(|(id, job)| {
+                                    if *id == job_id {
+                                        removed = Some(Arc::clone(job));
+                                        false
+                                    } else {
+                                        true
+                                    }
+                                });
+
+                                // Fire on_removed in its own task: the job
+                                // may still be mid-tick (and thus holding
+                                // its own lock) on the blocking thread pool,
+                                // and waiting for it here would stall the
+                                // dispatch loop for everyone else.
+                                if let Some(job) = removed {
+                                    tokio::spawn(async move {
+                                        let mut job = job.lock().await;
+                                        if let Some(on_removed) = &mut job.on_removed {
+                                            on_removed(job_id);
+                                        }
+                                    });
+                                }
+                            }
+                            Some(Command::Shutdown) | None => break,
+                        }
+                    }
+                }
+            }
+        });
+
+        (JobSchedulerHandle { commands: tx }, join_handle)
+    }
+}
+
+fn time_till_next_job<Tp: TimeProvider>(
+    jobs: &[(Uuid, Arc<Mutex<Job<'static>>>)],
+    time_provider: &Tp,
+    timezone: chrono::FixedOffset,
+) -> std::time::Duration {
+    if jobs.is_empty() {
+        return std::time::Duration::from_millis(500);
+    }
+
+    let now = time_provider.now().with_timezone(&timezone);
+    let mut duration = None;
+    for (_, job) in jobs {
+        // A job still running on its own spawned task holds its mutex for
+        // the duration of that run; skip it rather than blocking the whole
+        // loop on a potentially slow job.
+        let Ok(job) = job.try_lock() else {
+            continue;
+        };
+        if let Some(event) = job.next_event(now) {
+            let d = event - now;
+            duration = Some(duration.map_or(d, |duration: chrono::Duration| duration.min(d)));
+        }
+    }
+    duration.map_or(std::time::Duration::from_millis(500), |d| d.to_std().unwrap_or(std::time::Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn handle_add_runs_jobs_on_the_background_task() {
+        let sched = JobScheduler::new();
+        let (handle, join) = sched.start();
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let job_runs = Arc::clone(&runs);
+        handle.add(Job::interval(chrono::Duration::milliseconds(10), move || {
+            job_runs.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(runs.load(Ordering::SeqCst) >= 2);
+
+        handle.shutdown();
+        join.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn handle_remove_fires_on_removed() {
+        let sched = JobScheduler::new();
+        let (handle, join) = sched.start();
+
+        let removed = Arc::new(AtomicBool::new(false));
+        let removed_for_hook = Arc::clone(&removed);
+        let mut job = Job::interval(chrono::Duration::milliseconds(10), || {});
+        job.on_removed(move |_| removed_for_hook.store(true, Ordering::SeqCst));
+        let job_id = handle.add(job);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        handle.remove(job_id);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(removed.load(Ordering::SeqCst));
+
+        handle.shutdown();
+        join.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_job_that_finishes_itself_fires_on_removed() {
+        let sched = JobScheduler::new();
+        let (handle, join) = sched.start();
+
+        let removed = Arc::new(AtomicBool::new(false));
+        let removed_for_hook = Arc::clone(&removed);
+        let mut job = Job::interval(chrono::Duration::milliseconds(10), || {});
+        job.run_once();
+        job.on_removed(move |_| removed_for_hook.store(true, Ordering::SeqCst));
+        handle.add(job);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(removed.load(Ordering::SeqCst));
+
+        handle.shutdown();
+        join.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_blocking_job_does_not_stall_a_concurrent_fast_job() {
+        let sched = JobScheduler::new();
+        let (handle, join) = sched.start();
+
+        let mut slow_job = Job::interval(chrono::Duration::milliseconds(10), || {
+            std::thread::sleep(Duration::from_millis(200));
+        });
+        slow_job.run_once();
+        handle.add(slow_job);
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let job_runs = Arc::clone(&runs);
+        handle.add(Job::interval(chrono::Duration::milliseconds(10), move || {
+            job_runs.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(runs.load(Ordering::SeqCst) >= 2);
+
+        handle.shutdown();
+        join.await.unwrap();
+    }
+}